@@ -3,36 +3,33 @@ use std::array::TryFromSliceError;
 use std::str::FromStr;
 use std::fmt;
 
-use crate::chunk;
-
-#[derive(Debug, Eq, PartialEq)]
-struct ChunkType {
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct ChunkType {
     value: [u8; 4]
 }
 
 impl ChunkType {
-    fn bytes(&self) -> [u8; 4] {
+    pub fn bytes(&self) -> [u8; 4] {
         self.value
     }
 
-    fn is_valid(&self) -> bool {
-        let valid_types = ["IHDR", "PLTE", "IDAT", "IEND", "tEXt", "zTXt", "iTXt", "pHYs"].map(|s| ChunkType::from_str(s).unwrap());
-        valid_types.contains(self)
+    pub fn is_valid(&self) -> bool {
+        self.is_reserved_bit_valid() && self.value.iter().all(u8::is_ascii_alphabetic)
     }
 
-    fn is_critical(&self) -> bool {
+    pub fn is_critical(&self) -> bool {
         self.value[0] & (1 << 5) == 0
     }
 
-    fn is_public(&self) -> bool {
+    pub fn is_public(&self) -> bool {
         self.value[1] & (1 << 5) == 0
     }
 
-    fn is_reserved_bit_valid(&self) -> bool {
+    pub fn is_reserved_bit_valid(&self) -> bool {
         self.value[2] & (1 << 5) == 0
     }
 
-    fn is_safe_to_copy(&self) -> bool {
+    pub fn is_safe_to_copy(&self) -> bool {
         self.value[3] & (1 << 5) != 0
     }
 }
@@ -46,7 +43,7 @@ impl TryFrom<[u8; 4]> for ChunkType {
 }
 
 #[derive(Debug)]
-enum ChunkTypeError {
+pub enum ChunkTypeError {
     SizeError(TryFromSliceError),
     InvalidChunkType
 }
@@ -55,13 +52,12 @@ impl FromStr for ChunkType {
     type Err = ChunkTypeError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        // let chunk = ChunkType { value: s.as_bytes().try_into().map_err(ChunkTypeError::SizeError)? };
-        // if chunk.is_valid() {
-        //     Ok(chunk)
-        // } else {
-        //     Err(ChunkTypeError::InvalidChunkType)
-        // }
-        Ok(ChunkType { value: s.as_bytes().try_into().map_err(ChunkTypeError::SizeError)? })
+        let value: [u8; 4] = s.as_bytes().try_into().map_err(ChunkTypeError::SizeError)?;
+        if value.iter().all(u8::is_ascii_alphabetic) {
+            Ok(ChunkType { value })
+        } else {
+            Err(ChunkTypeError::InvalidChunkType)
+        }
     }
 }
 
@@ -0,0 +1,200 @@
+use std::fmt;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use rand::{rngs::OsRng, RngCore};
+use xsalsa20poly1305::aead::{Aead, KeyInit};
+use xsalsa20poly1305::{Key, Nonce, XSalsa20Poly1305};
+
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+const KEY_LEN: usize = 32;
+
+// Argon2id parameters baked into the header so a message encoded today can
+// still be decoded if the defaults change later.
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct KdfParams {
+    pub memory_kib: u32,
+    pub iterations: u32,
+    pub parallelism: u32,
+}
+
+impl KdfParams {
+    pub const DEFAULT: KdfParams = KdfParams {
+        memory_kib: Params::DEFAULT_M_COST,
+        iterations: Params::DEFAULT_T_COST,
+        parallelism: Params::DEFAULT_P_COST,
+    };
+
+    fn to_bytes(self) -> [u8; 12] {
+        let mut bytes = [0u8; 12];
+        bytes[0..4].copy_from_slice(&self.memory_kib.to_be_bytes());
+        bytes[4..8].copy_from_slice(&self.iterations.to_be_bytes());
+        bytes[8..12].copy_from_slice(&self.parallelism.to_be_bytes());
+        bytes
+    }
+
+    fn from_bytes(bytes: [u8; 12]) -> KdfParams {
+        KdfParams {
+            memory_kib: u32::from_be_bytes(bytes[0..4].try_into().unwrap()),
+            iterations: u32::from_be_bytes(bytes[4..8].try_into().unwrap()),
+            parallelism: u32::from_be_bytes(bytes[8..12].try_into().unwrap()),
+        }
+    }
+
+    fn argon2(self) -> Result<Argon2<'static>, CryptoError> {
+        let params = Params::new(self.memory_kib, self.iterations, self.parallelism, Some(KEY_LEN))
+            .map_err(|_| CryptoError::InvalidKdfParams)?;
+        Ok(Argon2::new(Algorithm::Argon2id, Version::V0x13, params))
+    }
+}
+
+const HEADER_LEN: usize = SALT_LEN + NONCE_LEN + 12;
+
+#[derive(Debug)]
+pub enum CryptoError {
+    HeaderTooShort,
+    InvalidKdfParams,
+    AuthenticationFailed,
+}
+
+impl fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CryptoError::HeaderTooShort => write!(f, "chunk data is too short to contain a crypto header"),
+            CryptoError::InvalidKdfParams => write!(f, "KDF parameters in the chunk header are out of range"),
+            CryptoError::AuthenticationFailed => write!(f, "message failed authentication: wrong passphrase or tampered chunk"),
+        }
+    }
+}
+
+fn derive_key(passphrase: &str, salt: &[u8; SALT_LEN], params: KdfParams) -> Result<Key, CryptoError> {
+    let mut key_bytes = [0u8; KEY_LEN];
+    params
+        .argon2()?
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key_bytes)
+        .map_err(|_| CryptoError::InvalidKdfParams)?;
+    Ok(Key::from(key_bytes))
+}
+
+/// Seals `plaintext` for `chunk_type` under `passphrase`, generating a fresh
+/// random salt and nonce for this call. The chunk's data is
+/// `salt || nonce || kdf_params || ciphertext`, where `ciphertext` already
+/// carries its Poly1305 tag courtesy of the AEAD construction.
+pub fn encrypt(chunk_type: ChunkType, passphrase: &str, plaintext: &[u8]) -> Result<Chunk, CryptoError> {
+    let params = KdfParams::DEFAULT;
+
+    let mut salt = [0u8; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let mut nonce_bytes = [0u8; NONCE_LEN];
+    OsRng.fill_bytes(&mut nonce_bytes);
+    let nonce = Nonce::from(nonce_bytes);
+
+    let key = derive_key(passphrase, &salt, params)?;
+    let cipher = XSalsa20Poly1305::new(&key);
+    let ciphertext = cipher.encrypt(&nonce, plaintext).map_err(|_| CryptoError::AuthenticationFailed)?;
+
+    let mut data = Vec::with_capacity(HEADER_LEN + ciphertext.len());
+    data.extend_from_slice(&salt);
+    data.extend_from_slice(&nonce_bytes);
+    data.extend_from_slice(&params.to_bytes());
+    data.extend_from_slice(&ciphertext);
+
+    Ok(Chunk::new(chunk_type, data))
+}
+
+pub fn decrypt(passphrase: &str, data: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    if data.len() < HEADER_LEN {
+        return Err(CryptoError::HeaderTooShort);
+    }
+
+    let salt: [u8; SALT_LEN] = data[0..SALT_LEN].try_into().unwrap();
+    let nonce_bytes: [u8; NONCE_LEN] = data[SALT_LEN..SALT_LEN + NONCE_LEN].try_into().unwrap();
+    let params = KdfParams::from_bytes(data[SALT_LEN + NONCE_LEN..HEADER_LEN].try_into().unwrap());
+
+    let key = derive_key(passphrase, &salt, params)?;
+    let cipher = XSalsa20Poly1305::new(&key);
+    let nonce = Nonce::from(nonce_bytes);
+
+    cipher
+        .decrypt(&nonce, &data[HEADER_LEN..])
+        .map_err(|_| CryptoError::AuthenticationFailed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_round_trip() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let chunk = encrypt(chunk_type, "correct horse battery staple", b"a secret message").unwrap();
+
+        let plaintext = decrypt("correct horse battery staple", chunk.data()).unwrap();
+        assert_eq!(plaintext, b"a secret message");
+    }
+
+    #[test]
+    fn test_each_encryption_uses_a_fresh_nonce() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let first = encrypt(chunk_type, "correct horse battery staple", b"a secret message").unwrap();
+        let second = encrypt(chunk_type, "correct horse battery staple", b"a secret message").unwrap();
+
+        let first_nonce = &first.data()[SALT_LEN..SALT_LEN + NONCE_LEN];
+        let second_nonce = &second.data()[SALT_LEN..SALT_LEN + NONCE_LEN];
+        assert_ne!(first_nonce, second_nonce);
+        assert_ne!(first.data()[HEADER_LEN..], second.data()[HEADER_LEN..]);
+    }
+
+    #[test]
+    fn test_wrong_passphrase_fails_authentication() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let chunk = encrypt(chunk_type, "correct horse battery staple", b"a secret message").unwrap();
+
+        let result = decrypt("wrong passphrase", chunk.data());
+        assert!(matches!(result, Err(CryptoError::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn test_tampered_ciphertext_fails_authentication() {
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let chunk = encrypt(chunk_type, "correct horse battery staple", b"a secret message").unwrap();
+
+        let mut data = chunk.data().to_vec();
+        let last = data.len() - 1;
+        data[last] ^= 0xFF;
+
+        let result = decrypt("correct horse battery staple", &data);
+        assert!(matches!(result, Err(CryptoError::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn test_swapped_ciphertext_bytes_fail_authentication() {
+        // A forgeable XOR-fold "MAC" would let two ciphertext bytes be
+        // swapped without tripping detection; a real Poly1305 tag must not.
+        let chunk_type = ChunkType::from_str("ruSt").unwrap();
+        let chunk = encrypt(chunk_type, "correct horse battery staple", b"a secret message, twice as long!").unwrap();
+
+        let mut data = chunk.data().to_vec();
+        let body_start = HEADER_LEN;
+        data.swap(body_start, body_start + 1);
+
+        let result = decrypt("correct horse battery staple", &data);
+        assert!(matches!(result, Err(CryptoError::AuthenticationFailed)));
+    }
+
+    #[test]
+    fn test_header_too_short_is_rejected() {
+        let result = decrypt("correct horse battery staple", &[0u8; HEADER_LEN - 1]);
+        assert!(matches!(result, Err(CryptoError::HeaderTooShort)));
+    }
+
+    #[test]
+    fn test_kdf_params_round_trip_through_bytes() {
+        let params = KdfParams::DEFAULT;
+        assert_eq!(KdfParams::from_bytes(params.to_bytes()), params);
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashSet;
+
+use crate::chunk::Chunk;
+use crate::split;
+
+/// A root identifies one chunk that a live message still depends on: its
+/// chunk type together with the `split` sequence index tagged into the
+/// chunk's data (sequence 0 for a payload that `split` emitted as a single
+/// segment). Chunks that `split` did not emit have no sequence and need no root.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+pub struct Root {
+    pub chunk_type: [u8; 4],
+    pub sequence: u32,
+}
+
+#[derive(Debug, Eq, PartialEq, Default)]
+pub struct GcStats {
+    pub chunks_scanned: usize,
+    pub chunks_removed: usize,
+    pub bytes_reclaimed: usize,
+}
+
+// PNG chunk framing that still exists on disk once a chunk is dropped:
+// a 4-byte length, the 4-byte type and the 4-byte CRC surrounding the data.
+const CHUNK_FRAMING_LEN: usize = 12;
+
+/// The `split` sequence index tagged into a chunk's data, or `None` when the
+/// chunk doesn't start with `split::MARKER`. Only `split` actually tags
+/// chunks with a sequence index; a `crypto::encrypt` chunk starts with a
+/// random salt and a `text` chunk starts with a keyword, either of which
+/// could be mistaken for an unrooted sequence number and swept as if it were
+/// an orphaned split segment. Gating on the marker keeps those out of GC's
+/// reach entirely.
+fn sequence_of(chunk: &Chunk) -> Option<u32> {
+    let data = chunk.data();
+    if data.len() < 8 || data[0..4] != split::MARKER {
+        return None;
+    }
+    Some(u32::from_be_bytes(data[4..8].try_into().unwrap()))
+}
+
+/// Sweeps `chunks` in place, dropping any ancillary, safe-to-copy chunk whose
+/// `(chunk_type, sequence)` is not in `roots`; chunks whose data does not start
+/// with `split::MARKER` are always kept. Critical chunks (IHDR/IDAT/IEND)
+/// and chunks that are unsafe to copy are never touched, mirroring the chunks
+/// a PNG viewer could not simply discard. `bytes_reclaimed` counts the full
+/// on-disk chunk size (length + type + data + CRC), not just the payload.
+pub fn collect_garbage(chunks: &mut Vec<Chunk>, roots: &HashSet<Root>) -> GcStats {
+    let mut stats = GcStats { chunks_scanned: chunks.len(), ..Default::default() };
+
+    chunks.retain(|chunk| {
+        let chunk_type = chunk.chunk_type();
+        if chunk_type.is_critical() || !chunk_type.is_safe_to_copy() {
+            return true;
+        }
+
+        let sequence = match sequence_of(chunk) {
+            Some(sequence) => sequence,
+            None => return true,
+        };
+
+        let root = Root { chunk_type: chunk_type.bytes(), sequence };
+        if roots.contains(&root) {
+            true
+        } else {
+            stats.chunks_removed += 1;
+            stats.bytes_reclaimed += CHUNK_FRAMING_LEN + chunk.data().len();
+            false
+        }
+    });
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chunk_type::ChunkType;
+    use std::str::FromStr;
+
+    fn ancillary_safe(sequence: u32, data: Vec<u8>) -> Chunk {
+        // lowercase/lowercase fourth letter => ancillary and safe-to-copy
+        let chunk_type = ChunkType::from_str("ruat").unwrap();
+        let mut bytes = split::MARKER.to_vec();
+        bytes.extend(sequence.to_be_bytes());
+        bytes.extend(data);
+        Chunk::new(chunk_type, bytes)
+    }
+
+    fn critical(data: Vec<u8>) -> Chunk {
+        let chunk_type = ChunkType::from_str("IHDR").unwrap();
+        Chunk::new(chunk_type, data)
+    }
+
+    #[test]
+    fn test_orphaned_chunk_is_removed() {
+        let mut chunks = vec![ancillary_safe(0, vec![1, 2, 3])];
+        let roots = HashSet::new();
+
+        let stats = collect_garbage(&mut chunks, &roots);
+
+        assert!(chunks.is_empty());
+        assert_eq!(stats.chunks_scanned, 1);
+        assert_eq!(stats.chunks_removed, 1);
+        assert_eq!(stats.bytes_reclaimed, CHUNK_FRAMING_LEN + split::MARKER.len() + 4 + 3);
+    }
+
+    #[test]
+    fn test_rooted_chunk_survives() {
+        let chunk = ancillary_safe(0, vec![1, 2, 3]);
+        let root = Root { chunk_type: chunk.chunk_type().bytes(), sequence: 0 };
+        let mut chunks = vec![chunk];
+        let mut roots = HashSet::new();
+        roots.insert(root);
+
+        let stats = collect_garbage(&mut chunks, &roots);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(stats.chunks_removed, 0);
+        assert_eq!(stats.bytes_reclaimed, 0);
+    }
+
+    #[test]
+    fn test_critical_chunk_is_never_removed() {
+        let mut chunks = vec![critical(vec![0; 13])];
+        let roots = HashSet::new();
+
+        let stats = collect_garbage(&mut chunks, &roots);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(stats.chunks_removed, 0);
+    }
+
+    #[test]
+    fn test_mixed_chunks_report_accurate_totals() {
+        let rooted = ancillary_safe(0, vec![9; 5]);
+        let root = Root { chunk_type: rooted.chunk_type().bytes(), sequence: 0 };
+        let orphaned = ancillary_safe(1, vec![9; 5]);
+        let mut chunks = vec![critical(vec![0; 13]), rooted, orphaned];
+        let mut roots = HashSet::new();
+        roots.insert(root);
+
+        let stats = collect_garbage(&mut chunks, &roots);
+
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(stats.chunks_scanned, 3);
+        assert_eq!(stats.chunks_removed, 1);
+        assert_eq!(stats.bytes_reclaimed, CHUNK_FRAMING_LEN + split::MARKER.len() + 4 + 5);
+    }
+
+    #[test]
+    fn test_untagged_chunk_is_never_swept() {
+        // Too short to carry a marker and sequence tag at all; such a chunk
+        // must not be assumed to be an orphaned sequence 0.
+        let chunk_type = ChunkType::from_str("ruat").unwrap();
+        let mut chunks = vec![Chunk::new(chunk_type, vec![1, 2])];
+        let roots = HashSet::new();
+
+        let stats = collect_garbage(&mut chunks, &roots);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(stats.chunks_removed, 0);
+    }
+
+    #[test]
+    fn test_non_split_chunk_is_never_swept() {
+        // A chunk whose data happens to be the right length but doesn't
+        // start with split::MARKER (e.g. a crypto::encrypt salt or a
+        // text::encode_ztxt keyword) must never be mistaken for an
+        // unrooted split segment and deleted.
+        let chunk_type = ChunkType::from_str("ruat").unwrap();
+        let lookalike_data = [0xAAu8; 8].to_vec();
+        assert_ne!(lookalike_data[0..4], split::MARKER);
+        let mut chunks = vec![Chunk::new(chunk_type, lookalike_data)];
+        let roots = HashSet::new();
+
+        let stats = collect_garbage(&mut chunks, &roots);
+
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(stats.chunks_removed, 0);
+    }
+}
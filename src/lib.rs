@@ -0,0 +1,6 @@
+pub mod chunk;
+pub mod chunk_type;
+pub mod crypto;
+pub mod gc;
+pub mod split;
+pub mod text;
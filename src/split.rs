@@ -0,0 +1,163 @@
+use crate::chunk::Chunk;
+use crate::chunk_type::ChunkType;
+
+const MIN_SIZE: usize = 2 * 1024;
+const NORMAL_SIZE: usize = 8 * 1024;
+const MAX_SIZE: usize = 64 * 1024;
+
+// More set bits than MASK_L so a zero match is rarer: keeps chunks growing
+// past MIN_SIZE before a cut becomes likely.
+const MASK_S: u64 = 0x0000_d932_0353_0000;
+// Fewer set bits than MASK_S so a zero match is commoner: pushes chunks to
+// cut soon after NORMAL_SIZE instead of drifting all the way to MAX_SIZE.
+const MASK_L: u64 = 0x0000_0035_0003_0000;
+
+const fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
+}
+
+const fn build_gear_table() -> [u64; 256] {
+    let mut table = [0u64; 256];
+    let mut i = 0;
+    while i < 256 {
+        table[i] = splitmix64(i as u64 + 1);
+        i += 1;
+    }
+    table
+}
+
+// Fixed, deterministically-seeded gear table: same input always yields the
+// same cut points, which is what makes dedup across re-encodings work.
+const GEAR: [u64; 256] = build_gear_table();
+
+// Prefixed onto every chunk this module emits, ahead of the sequence number,
+// so a reader (e.g. `gc`) can tell a split segment apart from an unrelated
+// chunk — a `crypto::encrypt` chunk starting with a random salt or a
+// `text::encode_ztxt` chunk starting with a keyword — that happens to share
+// the same ancillary, safe-to-copy chunk type.
+pub const MARKER: [u8; 4] = *b"fCDc";
+
+#[derive(Debug, Eq, PartialEq, Clone, Copy)]
+pub struct Segment {
+    pub sequence: u32,
+    pub offset: usize,
+    pub length: usize,
+}
+
+pub struct SplitResult {
+    pub chunks: Vec<Chunk>,
+    pub segments: Vec<Segment>,
+}
+
+fn next_cut(data: &[u8]) -> usize {
+    let limit = data.len().min(MAX_SIZE);
+    if limit <= MIN_SIZE {
+        return limit;
+    }
+
+    let mut fp: u64 = 0;
+    let mut i = MIN_SIZE;
+    while i < limit {
+        let b = data[i];
+        fp = (fp << 1).wrapping_add(GEAR[b as usize]);
+        let mask = if i < NORMAL_SIZE { MASK_S } else { MASK_L };
+        if fp & mask == 0 {
+            return i + 1;
+        }
+        i += 1;
+    }
+    limit
+}
+
+fn cut_points(payload: &[u8]) -> Vec<Segment> {
+    let mut segments = Vec::new();
+    let mut offset = 0;
+    let mut sequence = 0;
+    while offset < payload.len() {
+        let length = next_cut(&payload[offset..]);
+        segments.push(Segment { sequence, offset, length });
+        offset += length;
+        sequence += 1;
+    }
+    segments
+}
+
+pub fn split(chunk_type: ChunkType, payload: &[u8]) -> SplitResult {
+    let segments = cut_points(payload);
+    let chunks = segments
+        .iter()
+        .map(|segment| {
+            let mut data = Vec::with_capacity(8 + segment.length);
+            data.extend_from_slice(&MARKER);
+            data.extend_from_slice(&segment.sequence.to_be_bytes());
+            data.extend_from_slice(&payload[segment.offset..segment.offset + segment.length]);
+            Chunk::new(chunk_type, data)
+        })
+        .collect();
+
+    SplitResult { chunks, segments }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cut_points_cover_whole_payload() {
+        let payload = vec![0u8; MAX_SIZE * 3 + 17];
+        let segments = cut_points(&payload);
+
+        assert_eq!(segments.first().unwrap().offset, 0);
+        let total: usize = segments.iter().map(|s| s.length).sum();
+        assert_eq!(total, payload.len());
+    }
+
+    #[test]
+    fn test_cut_points_are_sequential() {
+        let payload = vec![1u8; MAX_SIZE * 2];
+        let segments = cut_points(&payload);
+
+        for (i, segment) in segments.iter().enumerate() {
+            assert_eq!(segment.sequence, i as u32);
+        }
+    }
+
+    #[test]
+    fn test_no_segment_exceeds_max_size() {
+        let payload = vec![7u8; MAX_SIZE * 4];
+        let segments = cut_points(&payload);
+
+        assert!(segments.iter().all(|s| s.length <= MAX_SIZE));
+    }
+
+    #[test]
+    fn test_small_payload_is_a_single_segment() {
+        let payload = vec![3u8; MIN_SIZE / 2];
+        let segments = cut_points(&payload);
+
+        assert_eq!(segments.len(), 1);
+        assert_eq!(segments[0].length, payload.len());
+    }
+
+    #[test]
+    fn test_identical_prefix_reuses_cut_points() {
+        let mut payload_a = vec![9u8; MAX_SIZE];
+        payload_a.extend_from_slice(b"a shared tail that should dedup");
+        let mut payload_b = vec![9u8; MAX_SIZE];
+        payload_b.extend_from_slice(b"a different tail entirely");
+
+        let segments_a = cut_points(&payload_a);
+        let segments_b = cut_points(&payload_b);
+
+        assert_eq!(segments_a[0], segments_b[0]);
+    }
+
+    #[test]
+    fn test_gear_table_is_deterministic() {
+        assert_eq!(GEAR, build_gear_table());
+        assert_ne!(GEAR[0], GEAR[1]);
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+use std::io::{Read, Write};
+
+use flate2::read::ZlibDecoder;
+use flate2::write::ZlibEncoder;
+use flate2::Compression;
+
+const COMPRESSION_METHOD_ZLIB: u8 = 0;
+
+#[derive(Debug)]
+pub enum TextError {
+    MissingNullSeparator,
+    UnknownCompressionMethod(u8),
+    CorruptCompressedStream,
+    InvalidUtf8,
+    Truncated,
+}
+
+impl fmt::Display for TextError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextError::MissingNullSeparator => write!(f, "keyword is not terminated by a null separator"),
+            TextError::UnknownCompressionMethod(m) => write!(f, "unknown compression method {}", m),
+            TextError::CorruptCompressedStream => write!(f, "zlib/DEFLATE stream is corrupt"),
+            TextError::InvalidUtf8 => write!(f, "text is not valid UTF-8"),
+            TextError::Truncated => write!(f, "chunk data ends before a required field"),
+        }
+    }
+}
+
+fn deflate(text: &[u8]) -> Vec<u8> {
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(text).expect("writing to an in-memory encoder cannot fail");
+    encoder.finish().expect("finishing an in-memory encoder cannot fail")
+}
+
+fn inflate(compressed: &[u8]) -> Result<Vec<u8>, TextError> {
+    let mut decoder = ZlibDecoder::new(compressed);
+    let mut out = Vec::new();
+    decoder.read_to_end(&mut out).map_err(|_| TextError::CorruptCompressedStream)?;
+    Ok(out)
+}
+
+/// zTXt layout: `keyword \0 compression_method deflate_stream`.
+pub fn encode_ztxt(keyword: &str, text: &str) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(keyword.as_bytes());
+    data.push(0);
+    data.push(COMPRESSION_METHOD_ZLIB);
+    data.extend_from_slice(&deflate(text.as_bytes()));
+    data
+}
+
+pub fn decode_ztxt(data: &[u8]) -> Result<(String, String), TextError> {
+    let separator = data.iter().position(|&b| b == 0).ok_or(TextError::MissingNullSeparator)?;
+    let keyword = data[..separator].iter().map(|&b| b as char).collect();
+
+    let method = *data.get(separator + 1).ok_or(TextError::Truncated)?;
+    if method != COMPRESSION_METHOD_ZLIB {
+        return Err(TextError::UnknownCompressionMethod(method));
+    }
+
+    let compressed = &data[separator + 2..];
+    let text = String::from_utf8(inflate(compressed)?).map_err(|_| TextError::InvalidUtf8)?;
+    Ok((keyword, text))
+}
+
+/// iTXt layout: `keyword \0 compression_flag compression_method language_tag \0
+/// translated_keyword \0 text`, where `text` is deflated when the flag is 1.
+pub fn encode_itxt(keyword: &str, language_tag: &str, translated_keyword: &str, text: &str, compressed: bool) -> Vec<u8> {
+    let mut data = Vec::new();
+    data.extend_from_slice(keyword.as_bytes());
+    data.push(0);
+    data.push(compressed as u8);
+    data.push(COMPRESSION_METHOD_ZLIB);
+    data.extend_from_slice(language_tag.as_bytes());
+    data.push(0);
+    data.extend_from_slice(translated_keyword.as_bytes());
+    data.push(0);
+
+    if compressed {
+        data.extend_from_slice(&deflate(text.as_bytes()));
+    } else {
+        data.extend_from_slice(text.as_bytes());
+    }
+    data
+}
+
+// Reads the compression flag and method bytes by explicit offset after the
+// keyword's separator rather than searching for the next null byte: both can
+// themselves be `0`, so a naive split would mistake them for separators. The
+// language tag and translated keyword are then found by their null separators.
+pub fn decode_itxt(data: &[u8]) -> Result<(String, String, String, String), TextError> {
+    let keyword_end = data.iter().position(|&b| b == 0).ok_or(TextError::MissingNullSeparator)?;
+    let keyword = data[..keyword_end].iter().map(|&b| b as char).collect();
+
+    let compressed = *data.get(keyword_end + 1).ok_or(TextError::Truncated)? != 0;
+    let method = *data.get(keyword_end + 2).ok_or(TextError::Truncated)?;
+    if method != COMPRESSION_METHOD_ZLIB {
+        return Err(TextError::UnknownCompressionMethod(method));
+    }
+
+    let after_flags = keyword_end + 3;
+    let language_end = data[after_flags..].iter().position(|&b| b == 0).map(|i| after_flags + i).ok_or(TextError::MissingNullSeparator)?;
+    let language_tag = String::from_utf8(data[after_flags..language_end].to_vec()).map_err(|_| TextError::InvalidUtf8)?;
+
+    let after_language = language_end + 1;
+    let translated_keyword_end = data[after_language..]
+        .iter()
+        .position(|&b| b == 0)
+        .map(|i| after_language + i)
+        .ok_or(TextError::MissingNullSeparator)?;
+    let translated_keyword = String::from_utf8(data[after_language..translated_keyword_end].to_vec()).map_err(|_| TextError::InvalidUtf8)?;
+
+    let text_bytes = &data[translated_keyword_end + 1..];
+    let text = if compressed {
+        String::from_utf8(inflate(text_bytes)?).map_err(|_| TextError::InvalidUtf8)?
+    } else {
+        String::from_utf8(text_bytes.to_vec()).map_err(|_| TextError::InvalidUtf8)?
+    };
+
+    Ok((keyword, language_tag, translated_keyword, text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ztxt_round_trip() {
+        let data = encode_ztxt("Comment", "a secret message hidden in plain sight");
+        let (keyword, text) = decode_ztxt(&data).unwrap();
+
+        assert_eq!(keyword, "Comment");
+        assert_eq!(text, "a secret message hidden in plain sight");
+    }
+
+    #[test]
+    fn test_ztxt_rejects_unknown_compression_method() {
+        let mut data = encode_ztxt("Comment", "hello");
+        let separator = data.iter().position(|&b| b == 0).unwrap();
+        data[separator + 1] = 7;
+
+        let result = decode_ztxt(&data);
+        assert!(matches!(result, Err(TextError::UnknownCompressionMethod(7))));
+    }
+
+    #[test]
+    fn test_ztxt_rejects_corrupt_stream() {
+        let mut data = encode_ztxt("Comment", "hello world");
+        let last = data.len() - 1;
+        data[last] ^= 0xFF;
+
+        let result = decode_ztxt(&data);
+        assert!(matches!(result, Err(TextError::CorruptCompressedStream)));
+    }
+
+    #[test]
+    fn test_itxt_round_trip_compressed() {
+        let data = encode_itxt("Title", "en", "Titre", "une charge utile unicode", true);
+        let (keyword, language_tag, translated_keyword, text) = decode_itxt(&data).unwrap();
+
+        assert_eq!(keyword, "Title");
+        assert_eq!(language_tag, "en");
+        assert_eq!(translated_keyword, "Titre");
+        assert_eq!(text, "une charge utile unicode");
+    }
+
+    #[test]
+    fn test_itxt_round_trip_uncompressed() {
+        let data = encode_itxt("Title", "en", "", "plain text", false);
+        let (keyword, language_tag, translated_keyword, text) = decode_itxt(&data).unwrap();
+
+        assert_eq!(keyword, "Title");
+        assert_eq!(language_tag, "en");
+        assert_eq!(translated_keyword, "");
+        assert_eq!(text, "plain text");
+    }
+}